@@ -3,6 +3,7 @@ use std::cmp::{PartialEq};
 use std::hash::{Hash, Hasher};
 use std::borrow::{Borrow, Cow};
 use std::fmt;
+use std::io::{self, Write};
 use std::str::{from_utf8, Utf8Error};
 
 use handle::Handle;
@@ -186,6 +187,68 @@ impl fmt::Debug for IBytes {
     }
 }
 
+/// Mutable builder for `IBytes`
+///
+/// `IBytesBuf` is like `IBytes`, but builds up incrementally and interns once, on `into_ibytes`.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct IBytesBuf(Vec<u8>);
+
+impl IBytesBuf {
+    #[inline]
+    pub fn new() -> Self {
+        IBytesBuf(Vec::new())
+    }
+
+    #[inline]
+    pub fn with_capacity(cap: usize) -> Self {
+        IBytesBuf(Vec::with_capacity(cap))
+    }
+
+    #[inline]
+    pub fn into_ibytes(self) -> IBytes {
+        IBytes::new(&self.0)
+    }
+}
+
+impl Deref for IBytesBuf {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Extend<u8> for IBytesBuf {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        self.0.extend(iter)
+    }
+}
+
+impl Write for IBytesBuf {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl From<IBytes> for IBytesBuf {
+    fn from(v: IBytes) -> Self {
+        IBytesBuf(v.as_bytes().to_vec())
+    }
+}
+
+impl fmt::Debug for IBytesBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
 #[cfg(feature = "serde-compat")]
 mod serde_compat {
     use super::*;
@@ -193,13 +256,59 @@ mod serde_compat {
 
     impl Serialize for IBytes {
         fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-            Serialize::serialize(self.as_bytes(), s)
+            #[cfg(any(feature = "serde-hex", feature = "serde-base64"))]
+            {
+                if s.is_human_readable() {
+                    return s.serialize_str(&encode_human_readable(self.as_bytes()));
+                }
+            }
+            s.serialize_bytes(self.as_bytes())
+        }
+    }
+
+    #[cfg(feature = "serde-hex")]
+    fn encode_human_readable(bytes: &[u8]) -> String {
+        use std::fmt::Write;
+        let mut out = String::with_capacity(bytes.len() * 2);
+        for b in bytes {
+            write!(out, "{:02x}", b).expect("writing to a String cannot fail");
+        }
+        out
+    }
+
+    #[cfg(feature = "serde-hex")]
+    fn decode_human_readable<E: de::Error>(value: &str) -> Result<Vec<u8>, E> {
+        let bytes = value.as_bytes();
+        if !value.is_ascii() || !bytes.len().is_multiple_of(2) {
+            return Err(E::custom("invalid hex string"));
         }
+        bytes
+            .chunks(2)
+            .map(|pair| {
+                let hi = (pair[0] as char).to_digit(16).ok_or_else(|| E::custom("invalid hex digit"))?;
+                let lo = (pair[1] as char).to_digit(16).ok_or_else(|| E::custom("invalid hex digit"))?;
+                Ok((hi as u8) << 4 | lo as u8)
+            })
+            .collect()
+    }
+
+    #[cfg(all(feature = "serde-base64", not(feature = "serde-hex")))]
+    fn encode_human_readable(bytes: &[u8]) -> String {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    }
+
+    #[cfg(all(feature = "serde-base64", not(feature = "serde-hex")))]
+    fn decode_human_readable<E: de::Error>(value: &str) -> Result<Vec<u8>, E> {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD
+            .decode(value)
+            .map_err(|e| E::custom(e))
     }
 
     impl<'d> Deserialize<'d> for IBytes {
         fn deserialize<D: Deserializer<'d>>(d: D) -> Result<IBytes, D::Error> {
-            d.deserialize_bytes(Visitor)
+            d.deserialize_byte_buf(Visitor)
         }
     }
 
@@ -215,5 +324,171 @@ mod serde_compat {
         fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<IBytes, E> {
             Ok(IBytes::new(value))
         }
+
+        fn visit_borrowed_bytes<E: de::Error>(self, value: &'d [u8]) -> Result<IBytes, E> {
+            Ok(IBytes::new(value))
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<IBytes, E> {
+            Ok(IBytes::new(&value))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<IBytes, E> {
+            #[cfg(any(feature = "serde-hex", feature = "serde-base64"))]
+            {
+                decode_human_readable(value).map(|buf| IBytes::new(&buf))
+            }
+            #[cfg(not(any(feature = "serde-hex", feature = "serde-base64")))]
+            {
+                Ok(IBytes::new(value.as_bytes()))
+            }
+        }
+
+        fn visit_borrowed_str<E: de::Error>(self, value: &'d str) -> Result<IBytes, E> {
+            self.visit_str(value)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'d>>(self, mut seq: A) -> Result<IBytes, A::Error> {
+            // Cap the upfront allocation: a corrupt/malicious payload can claim
+            // an arbitrarily large element count in its size hint.
+            let mut buf = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(byte) = seq.next_element()? {
+                buf.push(byte);
+            }
+            Ok(IBytes::new(&buf))
+        }
+    }
+
+    impl<'d> Deserialize<'d> for IBytesBuf {
+        fn deserialize<D: Deserializer<'d>>(d: D) -> Result<IBytesBuf, D::Error> {
+            d.deserialize_byte_buf(BufVisitor)
+        }
+    }
+
+    pub struct BufVisitor;
+
+    impl<'d> de::Visitor<'d> for BufVisitor {
+        type Value = IBytesBuf;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("byte slice")
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<IBytesBuf, E> {
+            Ok(IBytesBuf(value.to_vec()))
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<IBytesBuf, E> {
+            Ok(IBytesBuf(value))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<IBytesBuf, E> {
+            #[cfg(any(feature = "serde-hex", feature = "serde-base64"))]
+            {
+                decode_human_readable(value).map(IBytesBuf)
+            }
+            #[cfg(not(any(feature = "serde-hex", feature = "serde-base64")))]
+            {
+                Ok(IBytesBuf(value.as_bytes().to_vec()))
+            }
+        }
+
+        fn visit_borrowed_str<E: de::Error>(self, value: &'d str) -> Result<IBytesBuf, E> {
+            self.visit_str(value)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'d>>(self, mut seq: A) -> Result<IBytesBuf, A::Error> {
+            // Cap the upfront allocation: a corrupt/malicious payload can claim
+            // an arbitrarily large element count in its size hint.
+            let mut buf = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(byte) = seq.next_element()? {
+                buf.push(byte);
+            }
+            Ok(IBytesBuf(buf))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde-compat"))]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+    #[test]
+    fn ibytes_round_trips_as_bytes_on_binary_formats() {
+        let v = IBytes::new(b"hello");
+        assert_tokens(&v.compact(), &[Token::Bytes(b"hello")]);
+    }
+
+    #[test]
+    fn ibytes_deserializes_from_seq_like_json_array_of_u8() {
+        let v = IBytes::new(&[1, 2, 3]);
+        assert_de_tokens(
+            &v.compact(),
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn ibytes_deserializes_from_byte_buf() {
+        let v = IBytes::new(b"frame");
+        assert_de_tokens(&v.compact(), &[Token::ByteBuf(b"frame")]);
+    }
+
+    #[cfg(not(any(feature = "serde-hex", feature = "serde-base64")))]
+    #[test]
+    fn ibytes_deserializes_from_str_as_raw_utf8_without_human_readable_features() {
+        let v = IBytes::from_str("hello");
+        assert_de_tokens(&v.compact(), &[Token::Str("hello")]);
+    }
+
+    #[cfg(feature = "serde-hex")]
+    #[test]
+    fn ibytes_round_trips_as_hex_on_human_readable_formats() {
+        let v = IBytes::new(&[0xde, 0xad, 0xbe, 0xef]);
+        assert_tokens(&v.readable(), &[Token::Str("deadbeef")]);
+    }
+
+    #[cfg(feature = "serde-hex")]
+    #[test]
+    fn ibytes_hex_decode_rejects_non_ascii_instead_of_panicking() {
+        serde_test::assert_de_tokens_error::<IBytes>(
+            &[Token::Str("a\u{20ac}")],
+            "invalid hex string",
+        );
+    }
+
+    #[test]
+    fn ibytes_buf_interns_once_on_finalize() {
+        let mut buf = IBytesBuf::new();
+        buf.extend([1u8, 2, 3]);
+        assert_eq!(buf.into_ibytes().as_bytes(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn ibytes_buf_deserializes_from_seq_and_byte_buf() {
+        assert_de_tokens(
+            &IBytesBuf::from(IBytes::new(&[1, 2])),
+            &[Token::Seq { len: Some(2) }, Token::U8(1), Token::U8(2), Token::SeqEnd],
+        );
+        assert_de_tokens(
+            &IBytesBuf::from(IBytes::new(b"frame")),
+            &[Token::ByteBuf(b"frame")],
+        );
+    }
+
+    #[cfg(feature = "serde-hex")]
+    #[test]
+    fn ibytes_buf_decodes_hex_like_ibytes() {
+        assert_de_tokens(
+            &IBytesBuf::from(IBytes::new(&[0xde, 0xad])),
+            &[Token::Str("dead")],
+        );
     }
 }